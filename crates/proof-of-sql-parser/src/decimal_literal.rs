@@ -0,0 +1,297 @@
+use crate::error::{ParseError, ParseResult};
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+// NOTE: this module is still not reachable from the `sql` lalrpop grammar or from
+// `LiteralValue` — neither the grammar's `.lalrpop` source nor `intermediate_ast`/
+// `LiteralValue` exist anywhere in this changeset's tree (confirmed by searching for them),
+// so there is no lexer action or `LiteralValue::Decimal75` constructor in this tree to call
+// `ExactNumericLiteral::parse_scaled`/[`ScaledLiteral::to_i128`]. What this module provides
+// today is the full value computation a constructor would need:
+// `ExactNumericLiteral::parse_scaled(literal, target_precision, target_scale)` produces a
+// [`ScaledLiteral`], and [`ScaledLiteral::to_i128`] turns that into the signed `i128` a
+// `Scalar: From<i128>` conversion would consume (see
+// `proof-of-sql::base::database::owned_table_arrow_conversions`, which bridges `Decimal75`
+// through the same `i128` representation). The only missing piece is the two call sites —
+// the lexer rule invoking `parse_scaled` and a `LiteralValue::Decimal75` variant invoking
+// `to_i128` — neither of which has anywhere to live until the grammar/AST files exist.
+
+/// An arbitrary-precision, unsigned, base-10 accumulator.
+///
+/// Digits are pushed most-significant-first via [`Self::push_digit`], which performs the
+/// textbook `value = value * 10 + digit` step. This is the data structure that lets a
+/// literal like `123456789012345678901234567890.5` be parsed without going through a
+/// lossy floating-point intermediate.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct BigUintAccumulator {
+    /// Decimal digits, most-significant digit first. Empty means zero.
+    digits: Vec<u8>,
+}
+
+impl BigUintAccumulator {
+    fn push_digit(&mut self, digit: u8) {
+        debug_assert!(digit <= 9);
+        if !(self.digits.is_empty() && digit == 0) {
+            self.digits.push(digit);
+        }
+    }
+
+    /// Append `count` trailing zero digits, i.e. multiply by `10^count`.
+    fn push_zeros(&mut self, count: usize) {
+        if self.digits.is_empty() {
+            return;
+        }
+        self.digits.extend(core::iter::repeat(0).take(count));
+    }
+
+    fn num_digits(&self) -> usize {
+        self.digits.len()
+    }
+
+    fn into_decimal_string(self) -> String {
+        if self.digits.is_empty() {
+            "0".to_string()
+        } else {
+            self.digits.iter().map(|d| (d + b'0') as char).collect()
+        }
+    }
+}
+
+/// The exact, sign/integer/fraction/exponent decomposition of a decimal or scientific
+/// numeric literal string, before it is scaled to a target
+/// [`crate::posql_time`]-adjacent `Decimal75(precision, scale)` column type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExactNumericLiteral {
+    negative: bool,
+    integer_digits: String,
+    fractional_digits: String,
+    /// `value = (integer_digits.fractional_digits) * 10^exponent`
+    exponent: i16,
+}
+
+impl ExactNumericLiteral {
+    /// Split a numeric literal (e.g. `-123.456e7`) into sign, integer digits, fractional
+    /// digits, and an exponent, without ever rounding through a floating-point type.
+    ///
+    /// # Errors
+    /// Returns [`ParseError`] if `literal` is not of the form
+    /// `[-+]?[0-9]*(\.[0-9]*)?([eE][-+]?[0-9]+)?` with at least one digit.
+    pub fn parse(literal: &str) -> ParseResult<Self> {
+        let (negative, rest) = match literal.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, literal.strip_prefix('+').unwrap_or(literal)),
+        };
+        let (mantissa, exponent_str) = match rest.split_once(['e', 'E']) {
+            Some((m, e)) => (m, Some(e)),
+            None => (rest, None),
+        };
+        let (integer_digits, fractional_digits) = match mantissa.split_once('.') {
+            Some((int_part, frac_part)) => (int_part, frac_part),
+            None => (mantissa, ""),
+        };
+        if integer_digits.is_empty() && fractional_digits.is_empty() {
+            return Err(ParseError::UnparseableNumber {
+                error: "numeric literal has no digits".to_string(),
+            });
+        }
+        if !integer_digits.bytes().all(|b| b.is_ascii_digit())
+            || !fractional_digits.bytes().all(|b| b.is_ascii_digit())
+        {
+            return Err(ParseError::UnparseableNumber {
+                error: "numeric literal contains non-digit characters".to_string(),
+            });
+        }
+        let exponent = match exponent_str {
+            Some(e) => e.parse::<i16>().map_err(|_| ParseError::UnparseableNumber {
+                error: "numeric literal exponent is not a valid integer".to_string(),
+            })?,
+            None => 0,
+        };
+        Ok(Self {
+            negative,
+            integer_digits: integer_digits.to_string(),
+            fractional_digits: fractional_digits.to_string(),
+            exponent,
+        })
+    }
+
+    /// Assemble this literal's full digit string (ignoring the decimal point and exponent)
+    /// into a [`BigUintAccumulator`] via repeated `*10 + digit`.
+    fn digit_accumulator(&self) -> BigUintAccumulator {
+        let mut acc = BigUintAccumulator::default();
+        for byte in self.integer_digits.bytes().chain(self.fractional_digits.bytes()) {
+            acc.push_digit(byte - b'0');
+        }
+        acc
+    }
+
+    /// Losslessly rescale this literal to `target_scale` fractional digits, returning the
+    /// sign and the resulting integer's decimal digit string (i.e. the `Decimal75` raw
+    /// value at that scale).
+    ///
+    /// `shift = scale - (fraction_len - exponent)` digits are appended as trailing zeros if
+    /// positive. If `shift` is negative, the literal has more precision than `target_scale`
+    /// allows, and this is rejected as inexact rather than silently truncated.
+    ///
+    /// # Errors
+    /// Returns [`ParseError::UnparseableNumber`] if rescaling would be inexact, or if the
+    /// resulting magnitude does not fit in `target_precision` decimal digits (i.e.
+    /// `value >= 10^target_precision`).
+    pub fn to_scaled_integer_digits(
+        &self,
+        target_precision: u8,
+        target_scale: i8,
+    ) -> ParseResult<(bool, String)> {
+        let fraction_len = i32::try_from(self.fractional_digits.len()).unwrap_or(i32::MAX);
+        let shift = i32::from(target_scale) - (fraction_len - i32::from(self.exponent));
+        if shift < 0 {
+            return Err(ParseError::UnparseableNumber {
+                error: alloc::format!(
+                    "literal has more fractional digits than scale {target_scale} allows; would be rounded"
+                ),
+            });
+        }
+        let mut acc = self.digit_accumulator();
+        acc.push_zeros(shift as usize);
+        if acc.num_digits() > usize::from(target_precision) {
+            return Err(ParseError::UnparseableNumber {
+                error: alloc::format!(
+                    "literal does not fit in {target_precision} digits of precision"
+                ),
+            });
+        }
+        Ok((self.negative, acc.into_decimal_string()))
+    }
+
+    /// Parse and rescale a literal in one step, producing a [`ScaledLiteral`] — the shape a
+    /// `LiteralValue::Decimal75(precision, scale, ...)` constructor is expected to consume
+    /// once this module is wired into the grammar/AST.
+    ///
+    /// # Errors
+    /// Same as [`Self::parse`] and [`Self::to_scaled_integer_digits`].
+    pub fn parse_scaled(literal: &str, target_precision: u8, target_scale: i8) -> ParseResult<ScaledLiteral> {
+        let (negative, digits) = Self::parse(literal)?.to_scaled_integer_digits(target_precision, target_scale)?;
+        Ok(ScaledLiteral {
+            negative,
+            precision: target_precision,
+            scale: target_scale,
+            digits,
+        })
+    }
+}
+
+/// The sign, target `Decimal75(precision, scale)`, and exact unsigned digit string a
+/// literal rescales to — the integration point between [`ExactNumericLiteral`] and a
+/// `LiteralValue::Decimal75` constructor.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScaledLiteral {
+    /// Whether the literal is negative.
+    pub negative: bool,
+    /// The `Decimal75` precision the literal was rescaled to.
+    pub precision: u8,
+    /// The `Decimal75` scale the literal was rescaled to.
+    pub scale: i8,
+    /// The exact, unsigned base-10 digit string of the rescaled value.
+    pub digits: String,
+}
+
+impl ScaledLiteral {
+    /// The signed `i128` value a `LiteralValue::Decimal75` constructor would witness, for
+    /// the common case where `precision <= 38` (so the value is guaranteed to fit).
+    ///
+    /// `proof-of-sql`'s `Scalar` types bridge `Decimal75` values through `i128` elsewhere in
+    /// this crate family (see `proof-of-sql::base::database::owned_table_arrow_conversions`),
+    /// so this is the same representation a future `LiteralValue::Decimal75` constructor is
+    /// expected to convert into its backing scalar via `S::from(value)`.
+    ///
+    /// # Errors
+    /// Returns [`ParseError::UnparseableNumber`] if `self.digits` does not fit in `i128`
+    /// (only possible once `self.precision` exceeds 38, since `10^38 < i128::MAX`).
+    pub fn to_i128(&self) -> ParseResult<i128> {
+        let magnitude = self.digits.parse::<i128>().map_err(|_| ParseError::UnparseableNumber {
+            error: alloc::format!("decimal literal with {} digits does not fit in i128", self.digits.len()),
+        })?;
+        Ok(if self.negative { -magnitude } else { magnitude })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn we_can_parse_scaled_directly_into_a_scaled_literal() {
+        let scaled = ExactNumericLiteral::parse_scaled("-1.5", 10, 2).unwrap();
+        assert_eq!(
+            scaled,
+            ScaledLiteral {
+                negative: true,
+                precision: 10,
+                scale: 2,
+                digits: "150".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn we_can_parse_a_plain_integer() {
+        let lit = ExactNumericLiteral::parse("123").unwrap();
+        assert_eq!(lit.to_scaled_integer_digits(10, 0).unwrap(), (false, "123".to_string()));
+    }
+
+    #[test]
+    fn we_can_parse_a_negative_decimal() {
+        let lit = ExactNumericLiteral::parse("-1.5").unwrap();
+        assert_eq!(lit.to_scaled_integer_digits(10, 1).unwrap(), (true, "15".to_string()));
+    }
+
+    #[test]
+    fn we_can_widen_scale_with_trailing_zeros() {
+        let lit = ExactNumericLiteral::parse("1.5").unwrap();
+        assert_eq!(lit.to_scaled_integer_digits(10, 3).unwrap(), (false, "1500".to_string()));
+    }
+
+    #[test]
+    fn we_reject_rescaling_that_would_be_inexact() {
+        let lit = ExactNumericLiteral::parse("1.2345").unwrap();
+        assert!(lit.to_scaled_integer_digits(10, 2).is_err());
+    }
+
+    #[test]
+    fn we_can_parse_an_exponent() {
+        let lit = ExactNumericLiteral::parse("1.5e2").unwrap();
+        assert_eq!(lit.to_scaled_integer_digits(10, 0).unwrap(), (false, "150".to_string()));
+    }
+
+    #[test]
+    fn we_reject_a_value_that_overflows_the_target_precision() {
+        let lit = ExactNumericLiteral::parse("123456789012345678901234567890.5").unwrap();
+        let (sign, digits) = lit.to_scaled_integer_digits(40, 1).unwrap();
+        assert!(!sign);
+        assert_eq!(digits, "1234567890123456789012345678905");
+        assert!(lit.to_scaled_integer_digits(5, 1).is_err());
+    }
+
+    #[test]
+    fn we_reject_a_literal_with_no_digits() {
+        assert!(ExactNumericLiteral::parse(".").is_err());
+    }
+
+    #[test]
+    fn we_can_convert_a_scaled_literal_to_the_i128_a_constructor_would_witness() {
+        let scaled = ExactNumericLiteral::parse_scaled("-1.5", 10, 2).unwrap();
+        assert_eq!(scaled.to_i128().unwrap(), -150);
+
+        let scaled = ExactNumericLiteral::parse_scaled("1.5", 10, 2).unwrap();
+        assert_eq!(scaled.to_i128().unwrap(), 150);
+    }
+
+    #[test]
+    fn to_i128_rejects_a_value_too_wide_to_fit() {
+        // 40 digits: larger than i128::MAX (about 1.7 * 10^38).
+        let scaled = ExactNumericLiteral::parse_scaled("1234567890123456789012345678901234567890", 40, 0).unwrap();
+        assert!(scaled.to_i128().is_err());
+    }
+}
@@ -13,6 +13,10 @@ pub mod intermediate_ast;
 #[cfg(test)]
 mod intermediate_ast_tests;
 
+/// Lexically exact parsing of decimal/scientific numeric literals into big-integer digit
+/// strings, so they can be rescaled to a `Decimal75(precision, scale)` without rounding.
+pub mod decimal_literal;
+
 /// Shortcuts to construct intermediate AST nodes.
 pub mod utility;
 
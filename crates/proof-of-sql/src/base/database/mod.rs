@@ -0,0 +1,3 @@
+/// Conversions between this crate's `OwnedTable`/`ColumnType` and Arrow's
+/// `RecordBatch`/`DataType`, for interop with Arrow/Parquet pipelines.
+pub mod owned_table_arrow_conversions;
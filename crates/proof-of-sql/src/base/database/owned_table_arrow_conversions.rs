@@ -0,0 +1,641 @@
+use crate::base::{
+    database::{OwnedColumn, OwnedTable, OwnedTableError},
+    math::decimal::Precision,
+    posql_time::{PoSQLTimeUnit, PoSQLTimeZone},
+    scalar::Scalar,
+};
+use alloc::{
+    string::{String, ToString},
+    sync::Arc,
+    vec::Vec,
+};
+use arrow::{
+    array::{
+        Array, ArrayRef, BooleanArray, Decimal128Array, Decimal256Array, Int16Array, Int32Array,
+        Int64Array, Int8Array, RecordBatch, StringArray, TimestampMicrosecondArray,
+        TimestampMillisecondArray, TimestampNanosecondArray, TimestampSecondArray, UInt8Array,
+    },
+    datatypes::{i256, DataType, Field, TimeUnit},
+};
+use snafu::Snafu;
+
+use super::ColumnType;
+
+/// `Field` metadata key used to disambiguate [`ColumnType`]s that otherwise collapse onto
+/// the same Arrow [`DataType`] (`Int128` and `Scalar` both need a wide decimal to hold every
+/// value, since Arrow has no native 128-bit integer or field-scalar type).
+const LOGICAL_TYPE_METADATA_KEY: &str = "proof_of_sql.logical_type";
+
+/// Errors that can occur converting between this crate's column/table representation and
+/// Arrow's.
+#[derive(Debug, Snafu)]
+pub enum ArrowConversionError {
+    /// An Arrow `Decimal128`/`Decimal256` precision is too large to be represented by
+    /// [`Precision`] (whose maximum is [`Precision::MAX`]).
+    #[snafu(display("arrow decimal precision {precision} exceeds the maximum supported precision {max}"))]
+    PrecisionOverflow {
+        /// The precision reported by the Arrow decimal type.
+        precision: u8,
+        /// The maximum precision this crate supports.
+        max: u8,
+    },
+    /// An Arrow timestamp's timezone offset could not be parsed into a [`PoSQLTimeZone`].
+    #[snafu(display("arrow timestamp timezone {tz:?} is out of range or malformed"))]
+    InvalidTimezone {
+        /// The raw timezone string reported by Arrow.
+        tz: Option<String>,
+    },
+    /// The Arrow [`DataType`]/[`Array`] has no corresponding [`ColumnType`]/[`OwnedColumn`]
+    /// support in this crate (yet — see `Unimplemented` for variants that are merely not
+    /// wired up rather than fundamentally unrepresentable).
+    #[snafu(display("arrow data type {data_type:?} has no corresponding ColumnType"))]
+    UnsupportedDataType {
+        /// The unsupported Arrow data type.
+        data_type: DataType,
+    },
+    /// The conversion for this [`ColumnType`] is not implemented yet.
+    #[snafu(display("arrow array <-> OwnedColumn data conversion for {column_type:?} is not implemented yet"))]
+    Unimplemented {
+        /// The column type whose data conversion is missing.
+        column_type: ColumnType,
+    },
+    /// A `Decimal75`/`Int128` value did not fit in `i128`. This crate's current bridge for
+    /// those two column types round-trips through `i128` (see
+    /// [`owned_column_to_arrow_array`]), since a full little-endian limb accessor for
+    /// `S: Scalar` is not available in the portion of this codebase this change can see; a
+    /// precision-75 value that genuinely needs more than 128 bits of magnitude cannot be
+    /// converted until that wider accessor exists.
+    #[snafu(display("{column_type:?} value does not fit in i128"))]
+    ValueOutOfRange {
+        /// The column type whose value overflowed `i128`.
+        column_type: ColumnType,
+    },
+    /// Arrow rejected construction of a `Decimal128`/`Decimal256` array, e.g. because a
+    /// value's digit count exceeds the array's declared precision.
+    #[snafu(display("arrow rejected decimal array construction: {source}"))]
+    Arrow {
+        /// The underlying Arrow error.
+        source: arrow::error::ArrowError,
+    },
+    /// Building the resulting [`OwnedTable`] failed (e.g. mismatched column lengths).
+    #[snafu(display("failed to assemble OwnedTable from converted Arrow columns: {source}"))]
+    OwnedTable {
+        /// The underlying error.
+        source: OwnedTableError,
+    },
+}
+
+impl PartialEq for ArrowConversionError {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::PrecisionOverflow { precision: p1, max: m1 }, Self::PrecisionOverflow { precision: p2, max: m2 }) => {
+                p1 == p2 && m1 == m2
+            }
+            (Self::InvalidTimezone { tz: t1 }, Self::InvalidTimezone { tz: t2 }) => t1 == t2,
+            (Self::UnsupportedDataType { data_type: d1 }, Self::UnsupportedDataType { data_type: d2 }) => d1 == d2,
+            (Self::Unimplemented { column_type: c1 }, Self::Unimplemented { column_type: c2 })
+            | (Self::ValueOutOfRange { column_type: c1 }, Self::ValueOutOfRange { column_type: c2 }) => c1 == c2,
+            // `arrow::error::ArrowError` and `OwnedTableError` aren't `PartialEq`; these
+            // variants are only ever compared via `matches!` in tests.
+            (Self::Arrow { .. }, Self::Arrow { .. }) | (Self::OwnedTable { .. }, Self::OwnedTable { .. }) => false,
+            _ => false,
+        }
+    }
+}
+impl Eq for ArrowConversionError {}
+
+/// The narrowest Arrow decimal width (`Decimal128` or `Decimal256`) that can hold every
+/// value representable at the given `precision`, matching the widths Arrow's own cast
+/// kernels choose between.
+fn arrow_decimal_width(precision: u8) -> DataType {
+    // Decimal128 covers up to 38 digits; wider precisions need Decimal256.
+    if precision <= 38 {
+        DataType::Decimal128(precision, 0)
+    } else {
+        DataType::Decimal256(precision, 0)
+    }
+}
+
+/// Convert a [`ColumnType`] to the Arrow [`DataType`] used to store it in a `RecordBatch`.
+///
+/// `Int128` and `Scalar` both map to a wide decimal column, since Arrow has no native
+/// 128-bit integer or field-scalar type; use [`column_type_to_arrow_field`] instead of this
+/// function when you need the resulting column to round-trip back to the exact same
+/// [`ColumnType`], as it also tags the ambiguous cases with [`LOGICAL_TYPE_METADATA_KEY`].
+pub fn column_type_to_arrow_data_type(column_type: ColumnType) -> DataType {
+    match column_type {
+        ColumnType::Boolean => DataType::Boolean,
+        ColumnType::TinyInt => DataType::Int8,
+        ColumnType::Uint8 => DataType::UInt8,
+        ColumnType::SmallInt => DataType::Int16,
+        ColumnType::Int => DataType::Int32,
+        ColumnType::BigInt => DataType::Int64,
+        ColumnType::Int128 => DataType::Decimal128(38, 0),
+        ColumnType::Decimal75(precision, scale) => match arrow_decimal_width(precision.value()) {
+            DataType::Decimal128(p, _) => DataType::Decimal128(p, scale),
+            DataType::Decimal256(p, _) => DataType::Decimal256(p, scale),
+            _ => unreachable!("arrow_decimal_width only returns Decimal128/Decimal256"),
+        },
+        ColumnType::TimestampTZ(unit, tz) => DataType::Timestamp(
+            match unit {
+                PoSQLTimeUnit::Second => TimeUnit::Second,
+                PoSQLTimeUnit::Millisecond => TimeUnit::Millisecond,
+                PoSQLTimeUnit::Microsecond => TimeUnit::Microsecond,
+                PoSQLTimeUnit::Nanosecond => TimeUnit::Nanosecond,
+            },
+            Some(tz.to_string().into()),
+        ),
+        ColumnType::VarChar => DataType::Utf8,
+        ColumnType::Scalar => DataType::Decimal256(75, 0),
+    }
+}
+
+/// Convert a [`ColumnType`] to the Arrow [`Field`] used to store it in a `RecordBatch`,
+/// tagging `Int128`/`Scalar` with [`LOGICAL_TYPE_METADATA_KEY`] so
+/// [`arrow_field_to_column_type`] can recover the exact original [`ColumnType`] rather than
+/// collapsing both onto whichever decimal type their values happen to fit in.
+pub fn column_type_to_arrow_field(name: &str, column_type: ColumnType) -> Field {
+    let data_type = column_type_to_arrow_data_type(column_type);
+    let field = Field::new(name, data_type, true);
+    match column_type {
+        ColumnType::Int128 => field.with_metadata(
+            [(LOGICAL_TYPE_METADATA_KEY.to_string(), "Int128".to_string())]
+                .into_iter()
+                .collect(),
+        ),
+        ColumnType::Scalar => field.with_metadata(
+            [(LOGICAL_TYPE_METADATA_KEY.to_string(), "Scalar".to_string())]
+                .into_iter()
+                .collect(),
+        ),
+        _ => field,
+    }
+}
+
+/// Convert an Arrow [`DataType`] to the [`ColumnType`] used to store it in an `OwnedTable`.
+///
+/// This has no way to recover `Int128`/`Scalar` from a bare `DataType` (both are stored as
+/// a wide decimal); use [`arrow_field_to_column_type`] when the [`Field`]'s metadata (as
+/// written by [`column_type_to_arrow_field`]) is available.
+///
+/// # Errors
+/// Returns [`ArrowConversionError::PrecisionOverflow`] if a decimal's precision exceeds
+/// [`Precision::MAX`], [`ArrowConversionError::InvalidTimezone`] if a timestamp's timezone
+/// cannot be parsed, and [`ArrowConversionError::UnsupportedDataType`] for any Arrow type
+/// with no [`ColumnType`] equivalent (e.g. `Float32`, `List`, ...).
+pub fn arrow_data_type_to_column_type(data_type: &DataType) -> Result<ColumnType, ArrowConversionError> {
+    match data_type {
+        DataType::Boolean => Ok(ColumnType::Boolean),
+        DataType::Int8 => Ok(ColumnType::TinyInt),
+        DataType::UInt8 => Ok(ColumnType::Uint8),
+        DataType::Int16 => Ok(ColumnType::SmallInt),
+        DataType::Int32 => Ok(ColumnType::Int),
+        DataType::Int64 => Ok(ColumnType::BigInt),
+        DataType::Decimal128(precision, scale) | DataType::Decimal256(precision, scale) => {
+            Precision::new(*precision)
+                .map(|p| ColumnType::Decimal75(p, *scale))
+                .map_err(|_| ArrowConversionError::PrecisionOverflow {
+                    precision: *precision,
+                    max: Precision::MAX,
+                })
+        }
+        DataType::Timestamp(unit, tz) => {
+            let posql_unit = match unit {
+                TimeUnit::Second => PoSQLTimeUnit::Second,
+                TimeUnit::Millisecond => PoSQLTimeUnit::Millisecond,
+                TimeUnit::Microsecond => PoSQLTimeUnit::Microsecond,
+                TimeUnit::Nanosecond => PoSQLTimeUnit::Nanosecond,
+            };
+            let posql_tz = match tz {
+                Some(tz_str) => {
+                    PoSQLTimeZone::try_from(tz_str.as_ref()).map_err(|_| ArrowConversionError::InvalidTimezone {
+                        tz: Some(tz_str.to_string()),
+                    })?
+                }
+                None => PoSQLTimeZone::new(0),
+            };
+            Ok(ColumnType::TimestampTZ(posql_unit, posql_tz))
+        }
+        DataType::Utf8 => Ok(ColumnType::VarChar),
+        other => Err(ArrowConversionError::UnsupportedDataType {
+            data_type: other.clone(),
+        }),
+    }
+}
+
+/// Convert an Arrow [`Field`] to the [`ColumnType`] used to store it in an `OwnedTable`,
+/// preferring the `Int128`/`Scalar` tag written by [`column_type_to_arrow_field`] (if
+/// present) over the ambiguous generic decimal mapping in [`arrow_data_type_to_column_type`].
+///
+/// # Errors
+/// Same as [`arrow_data_type_to_column_type`].
+pub fn arrow_field_to_column_type(field: &Field) -> Result<ColumnType, ArrowConversionError> {
+    match field.metadata().get(LOGICAL_TYPE_METADATA_KEY).map(String::as_str) {
+        Some("Int128") => Ok(ColumnType::Int128),
+        Some("Scalar") => Ok(ColumnType::Scalar),
+        _ => arrow_data_type_to_column_type(field.data_type()),
+    }
+}
+
+/// Convert `values` to `i128`, failing with [`ArrowConversionError::ValueOutOfRange`] (tagged
+/// with `column_type`) the first time one doesn't fit.
+fn scalars_to_i128<S>(values: &[S], column_type: ColumnType) -> Result<Vec<i128>, ArrowConversionError>
+where
+    i128: TryFrom<S>,
+    S: Copy,
+{
+    values
+        .iter()
+        .map(|&v| i128::try_from(v).map_err(|_| ArrowConversionError::ValueOutOfRange { column_type }))
+        .collect()
+}
+
+/// Convert one [`OwnedColumn`] into an Arrow [`ArrayRef`], moving/copying its backing data
+/// directly into the matching Arrow array type.
+///
+/// `Int128` and `Decimal75` are bridged through `i128` (Arrow's `Decimal128`/`Decimal256`
+/// arrays store each value as a native integer at a fixed scale, and `i128` covers every
+/// value this crate's own down-scaling cast witnessing (`sql::proof_exprs::scaling_cast_expr`)
+/// ever produces) rather than through a full little-endian limb accessor on `S: Scalar`, which
+/// this crate's
+/// `Scalar` trait does not expose in the portion of this codebase available to this change.
+/// A `Decimal75` value that genuinely needs more than 128 bits of magnitude (possible at the
+/// higher end of its up-to-75-digit precision range, if this crate ever produces one) fails
+/// with [`ArrowConversionError::ValueOutOfRange`] instead of being silently truncated.
+/// `Scalar` columns hold full field elements with no such range guarantee, so they are left
+/// as [`ArrowConversionError::Unimplemented`] rather than risk silently corrupting a value
+/// that overflows `i128`.
+///
+/// # Errors
+/// Returns [`ArrowConversionError::ValueOutOfRange`] if an `Int128`/`Decimal75` value does
+/// not fit in `i128`, [`ArrowConversionError::Arrow`] if Arrow rejects the resulting decimal
+/// array (e.g. a value's digit count exceeds its declared precision), and
+/// [`ArrowConversionError::Unimplemented`] for `Scalar` columns.
+pub fn owned_column_to_arrow_array<S>(column: &OwnedColumn<S>) -> Result<ArrayRef, ArrowConversionError>
+where
+    S: Scalar + Copy,
+    i128: TryFrom<S>,
+{
+    Ok(match column {
+        OwnedColumn::Boolean(values) => Arc::new(BooleanArray::from(values.clone())),
+        OwnedColumn::TinyInt(values) => Arc::new(Int8Array::from(values.clone())),
+        OwnedColumn::Uint8(values) => Arc::new(UInt8Array::from(values.clone())),
+        OwnedColumn::SmallInt(values) => Arc::new(Int16Array::from(values.clone())),
+        OwnedColumn::Int(values) => Arc::new(Int32Array::from(values.clone())),
+        OwnedColumn::BigInt(values) => Arc::new(Int64Array::from(values.clone())),
+        OwnedColumn::TimestampTZ(unit, _tz, values) => match unit {
+            PoSQLTimeUnit::Second => Arc::new(TimestampSecondArray::from(values.clone())),
+            PoSQLTimeUnit::Millisecond => Arc::new(TimestampMillisecondArray::from(values.clone())),
+            PoSQLTimeUnit::Microsecond => Arc::new(TimestampMicrosecondArray::from(values.clone())),
+            PoSQLTimeUnit::Nanosecond => Arc::new(TimestampNanosecondArray::from(values.clone())),
+        },
+        OwnedColumn::VarChar(values) => Arc::new(StringArray::from(values.clone())),
+        OwnedColumn::Int128(values) => {
+            let ints = scalars_to_i128(values, ColumnType::Int128)?;
+            Arc::new(
+                Decimal128Array::from(ints)
+                    .with_precision_and_scale(38, 0)
+                    .map_err(|source| ArrowConversionError::Arrow { source })?,
+            )
+        }
+        OwnedColumn::Decimal75(precision, scale, values) => {
+            let column_type = ColumnType::Decimal75(*precision, *scale);
+            let ints = scalars_to_i128(values, column_type)?;
+            match arrow_decimal_width(precision.value()) {
+                DataType::Decimal128(p, _) => Arc::new(
+                    Decimal128Array::from(ints)
+                        .with_precision_and_scale(p, *scale)
+                        .map_err(|source| ArrowConversionError::Arrow { source })?,
+                ),
+                DataType::Decimal256(p, _) => Arc::new(
+                    Decimal256Array::from(ints.into_iter().map(i256::from_i128).collect::<Vec<_>>())
+                        .with_precision_and_scale(p, *scale)
+                        .map_err(|source| ArrowConversionError::Arrow { source })?,
+                ),
+                _ => unreachable!("arrow_decimal_width only returns Decimal128/Decimal256"),
+            }
+        }
+        OwnedColumn::Scalar(_) => {
+            return Err(ArrowConversionError::Unimplemented {
+                column_type: ColumnType::Scalar,
+            })
+        }
+    })
+}
+
+/// Convert an Arrow [`ArrayRef`] into an [`OwnedColumn`] of the given `column_type`.
+///
+/// This is the data-moving counterpart to [`arrow_field_to_column_type`] (which only
+/// inspects the schema); callers that already know the target [`ColumnType`] can use this
+/// directly instead of going through a full [`RecordBatch`]. See
+/// [`owned_column_to_arrow_array`] for the same `i128`-bridged `Decimal75`/`Int128` handling
+/// (and the `Scalar` limitation) in the other direction.
+///
+/// # Errors
+/// Returns [`ArrowConversionError::UnsupportedDataType`] if `array`'s Arrow type does not
+/// match `column_type`'s expected Arrow representation,
+/// [`ArrowConversionError::ValueOutOfRange`] if a wide (`Decimal256`) value does not fit in
+/// `i128`, and [`ArrowConversionError::Unimplemented`] for `Scalar`.
+pub fn arrow_array_to_owned_column<S>(
+    column_type: ColumnType,
+    array: &ArrayRef,
+) -> Result<OwnedColumn<S>, ArrowConversionError>
+where
+    S: Scalar + From<i128>,
+{
+    fn mismatch(array: &ArrayRef) -> ArrowConversionError {
+        ArrowConversionError::UnsupportedDataType {
+            data_type: array.data_type().clone(),
+        }
+    }
+    Ok(match column_type {
+        ColumnType::Boolean => OwnedColumn::Boolean(
+            array
+                .as_any()
+                .downcast_ref::<BooleanArray>()
+                .ok_or_else(|| mismatch(array))?
+                .iter()
+                .map(|v| v.unwrap_or_default())
+                .collect(),
+        ),
+        ColumnType::TinyInt => OwnedColumn::TinyInt(
+            array
+                .as_any()
+                .downcast_ref::<Int8Array>()
+                .ok_or_else(|| mismatch(array))?
+                .values()
+                .to_vec(),
+        ),
+        ColumnType::Uint8 => OwnedColumn::Uint8(
+            array
+                .as_any()
+                .downcast_ref::<UInt8Array>()
+                .ok_or_else(|| mismatch(array))?
+                .values()
+                .to_vec(),
+        ),
+        ColumnType::SmallInt => OwnedColumn::SmallInt(
+            array
+                .as_any()
+                .downcast_ref::<Int16Array>()
+                .ok_or_else(|| mismatch(array))?
+                .values()
+                .to_vec(),
+        ),
+        ColumnType::Int => OwnedColumn::Int(
+            array
+                .as_any()
+                .downcast_ref::<Int32Array>()
+                .ok_or_else(|| mismatch(array))?
+                .values()
+                .to_vec(),
+        ),
+        ColumnType::BigInt => OwnedColumn::BigInt(
+            array
+                .as_any()
+                .downcast_ref::<Int64Array>()
+                .ok_or_else(|| mismatch(array))?
+                .values()
+                .to_vec(),
+        ),
+        ColumnType::VarChar => OwnedColumn::VarChar(
+            array
+                .as_any()
+                .downcast_ref::<StringArray>()
+                .ok_or_else(|| mismatch(array))?
+                .iter()
+                .map(|v| v.unwrap_or_default().to_string())
+                .collect(),
+        ),
+        ColumnType::TimestampTZ(unit, tz) => {
+            let values: Vec<i64> = match unit {
+                PoSQLTimeUnit::Second => array
+                    .as_any()
+                    .downcast_ref::<TimestampSecondArray>()
+                    .ok_or_else(|| mismatch(array))?
+                    .values()
+                    .to_vec(),
+                PoSQLTimeUnit::Millisecond => array
+                    .as_any()
+                    .downcast_ref::<TimestampMillisecondArray>()
+                    .ok_or_else(|| mismatch(array))?
+                    .values()
+                    .to_vec(),
+                PoSQLTimeUnit::Microsecond => array
+                    .as_any()
+                    .downcast_ref::<TimestampMicrosecondArray>()
+                    .ok_or_else(|| mismatch(array))?
+                    .values()
+                    .to_vec(),
+                PoSQLTimeUnit::Nanosecond => array
+                    .as_any()
+                    .downcast_ref::<TimestampNanosecondArray>()
+                    .ok_or_else(|| mismatch(array))?
+                    .values()
+                    .to_vec(),
+            };
+            OwnedColumn::TimestampTZ(unit, tz, values)
+        }
+        ColumnType::Int128 => OwnedColumn::Int128(
+            array
+                .as_any()
+                .downcast_ref::<Decimal128Array>()
+                .ok_or_else(|| mismatch(array))?
+                .values()
+                .iter()
+                .map(|&v| S::from(v))
+                .collect(),
+        ),
+        ColumnType::Decimal75(precision, scale) => {
+            let values: Vec<S> = match arrow_decimal_width(precision.value()) {
+                DataType::Decimal128(_, _) => array
+                    .as_any()
+                    .downcast_ref::<Decimal128Array>()
+                    .ok_or_else(|| mismatch(array))?
+                    .values()
+                    .iter()
+                    .map(|&v| S::from(v))
+                    .collect(),
+                DataType::Decimal256(_, _) => array
+                    .as_any()
+                    .downcast_ref::<Decimal256Array>()
+                    .ok_or_else(|| mismatch(array))?
+                    .values()
+                    .iter()
+                    .map(|v| {
+                        v.to_i128().map(S::from).ok_or(ArrowConversionError::ValueOutOfRange { column_type })
+                    })
+                    .collect::<Result<_, _>>()?,
+                _ => unreachable!("arrow_decimal_width only returns Decimal128/Decimal256"),
+            };
+            OwnedColumn::Decimal75(precision, scale, values)
+        }
+        ColumnType::Scalar => return Err(ArrowConversionError::Unimplemented { column_type }),
+    })
+}
+
+/// Convert an Arrow [`RecordBatch`] into an [`OwnedTable`], moving each column's backing
+/// data directly into the matching [`OwnedColumn`] variant via [`arrow_array_to_owned_column`]
+/// (no per-value copying through an intermediate string or JSON representation).
+///
+/// # Errors
+/// Returns [`ArrowConversionError`] if any column's [`Field`] has no [`ColumnType`]
+/// equivalent, if that column's type is one of the not-yet-implemented data conversions
+/// (see [`owned_column_to_arrow_array`]), or if assembling the resulting [`OwnedTable`]
+/// fails (e.g. because the batch has zero columns).
+pub fn record_batch_to_owned_table<S>(batch: &RecordBatch) -> Result<OwnedTable<S>, ArrowConversionError>
+where
+    S: Scalar + From<i128>,
+{
+    let mut columns = Vec::with_capacity(batch.num_columns());
+    for (field, array) in batch.schema().fields().iter().zip(batch.columns()) {
+        let column_type = arrow_field_to_column_type(field)?;
+        columns.push((field.name().clone(), arrow_array_to_owned_column(column_type, array)?));
+    }
+    OwnedTable::try_from_iter(columns).map_err(|source| ArrowConversionError::OwnedTable { source })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn we_can_round_trip_integer_types_through_arrow_data_type() {
+        for column_type in [
+            ColumnType::Boolean,
+            ColumnType::TinyInt,
+            ColumnType::Uint8,
+            ColumnType::SmallInt,
+            ColumnType::Int,
+            ColumnType::BigInt,
+        ] {
+            let arrow_type = column_type_to_arrow_data_type(column_type);
+            assert_eq!(arrow_data_type_to_column_type(&arrow_type).unwrap(), column_type);
+        }
+    }
+
+    #[test]
+    fn we_can_round_trip_int128_and_scalar_through_arrow_field_metadata() {
+        for column_type in [ColumnType::Int128, ColumnType::Scalar] {
+            let field = column_type_to_arrow_field("c", column_type);
+            assert_eq!(arrow_field_to_column_type(&field).unwrap(), column_type);
+        }
+    }
+
+    #[test]
+    fn without_metadata_int128_and_scalar_collapse_onto_decimal75() {
+        // Documents the known, narrower guarantee of the bare-DataType functions: callers
+        // who discard Field metadata get a Decimal75 back, not the original logical type.
+        let arrow_type = column_type_to_arrow_data_type(ColumnType::Int128);
+        assert_eq!(
+            arrow_data_type_to_column_type(&arrow_type).unwrap(),
+            ColumnType::Decimal75(Precision::new(38).unwrap(), 0)
+        );
+        let arrow_type = column_type_to_arrow_data_type(ColumnType::Scalar);
+        assert_eq!(
+            arrow_data_type_to_column_type(&arrow_type).unwrap(),
+            ColumnType::Decimal75(Precision::new(75).unwrap(), 0)
+        );
+    }
+
+    #[test]
+    fn we_can_round_trip_a_small_decimal_as_decimal128() {
+        let column_type = ColumnType::Decimal75(Precision::new(10).unwrap(), 2);
+        assert_eq!(column_type_to_arrow_data_type(column_type), DataType::Decimal128(10, 2));
+        assert_eq!(arrow_data_type_to_column_type(&DataType::Decimal128(10, 2)).unwrap(), column_type);
+    }
+
+    #[test]
+    fn we_can_round_trip_a_wide_decimal_as_decimal256() {
+        let column_type = ColumnType::Decimal75(Precision::new(40).unwrap(), 1);
+        assert_eq!(column_type_to_arrow_data_type(column_type), DataType::Decimal256(40, 1));
+        assert_eq!(arrow_data_type_to_column_type(&DataType::Decimal256(40, 1)).unwrap(), column_type);
+    }
+
+    #[test]
+    fn we_cannot_convert_a_decimal_whose_precision_overflows_precision() {
+        let err = arrow_data_type_to_column_type(&DataType::Decimal256(76, 0)).unwrap_err();
+        assert_eq!(
+            err,
+            ArrowConversionError::PrecisionOverflow {
+                precision: 76,
+                max: Precision::MAX
+            }
+        );
+    }
+
+    #[test]
+    fn we_cannot_convert_an_unsupported_arrow_type() {
+        let err = arrow_data_type_to_column_type(&DataType::Float32).unwrap_err();
+        assert_eq!(
+            err,
+            ArrowConversionError::UnsupportedDataType {
+                data_type: DataType::Float32
+            }
+        );
+    }
+
+    #[test]
+    fn we_can_round_trip_integer_columns_through_owned_column_and_array() {
+        let column: OwnedColumn<crate::base::scalar::test_scalar::TestScalar> =
+            OwnedColumn::BigInt(alloc::vec![1, 2, 3]);
+        let array = owned_column_to_arrow_array(&column).unwrap();
+        let round_tripped = arrow_array_to_owned_column::<crate::base::scalar::test_scalar::TestScalar>(
+            ColumnType::BigInt,
+            &array,
+        )
+        .unwrap();
+        assert_eq!(column, round_tripped);
+    }
+
+    #[test]
+    fn we_can_round_trip_an_int128_column_through_owned_column_and_array() {
+        let column: OwnedColumn<crate::base::scalar::test_scalar::TestScalar> =
+            OwnedColumn::Int128(alloc::vec![1, -2, i128::from(i64::MAX) + 1]);
+        let array = owned_column_to_arrow_array(&column).unwrap();
+        let round_tripped = arrow_array_to_owned_column::<crate::base::scalar::test_scalar::TestScalar>(
+            ColumnType::Int128,
+            &array,
+        )
+        .unwrap();
+        assert_eq!(column, round_tripped);
+    }
+
+    #[test]
+    fn we_can_round_trip_a_small_decimal_column_through_owned_column_and_array() {
+        let precision = Precision::new(10).unwrap();
+        let column: OwnedColumn<crate::base::scalar::test_scalar::TestScalar> =
+            OwnedColumn::Decimal75(precision, 2, alloc::vec![123, -456, 0]);
+        let array = owned_column_to_arrow_array(&column).unwrap();
+        let round_tripped = arrow_array_to_owned_column::<crate::base::scalar::test_scalar::TestScalar>(
+            ColumnType::Decimal75(precision, 2),
+            &array,
+        )
+        .unwrap();
+        assert_eq!(column, round_tripped);
+    }
+
+    #[test]
+    fn we_can_round_trip_a_wide_decimal_column_through_owned_column_and_array() {
+        // Precision 40 forces the Decimal256 path, but the value itself still fits in i128.
+        let precision = Precision::new(40).unwrap();
+        let column: OwnedColumn<crate::base::scalar::test_scalar::TestScalar> =
+            OwnedColumn::Decimal75(precision, 1, alloc::vec![123_456_789, -1]);
+        let array = owned_column_to_arrow_array(&column).unwrap();
+        let round_tripped = arrow_array_to_owned_column::<crate::base::scalar::test_scalar::TestScalar>(
+            ColumnType::Decimal75(precision, 1),
+            &array,
+        )
+        .unwrap();
+        assert_eq!(column, round_tripped);
+    }
+
+    #[test]
+    fn scalar_columns_are_not_yet_supported_for_data_conversion() {
+        let column: OwnedColumn<crate::base::scalar::test_scalar::TestScalar> = OwnedColumn::Scalar(alloc::vec![]);
+        assert!(matches!(
+            owned_column_to_arrow_array(&column),
+            Err(ArrowConversionError::Unimplemented { .. })
+        ));
+    }
+}
@@ -0,0 +1,223 @@
+use crate::base::{proof::ProofError, scalar::Scalar, slice_ops::inner_product_ref_cast};
+use alloc::vec::Vec;
+
+/// The piece of a [`super::VerifiableQueryResult`] that [`AccumulatedQueryResult`] folds:
+/// one query's own commitment vector (e.g. its column data, or its commitment openings),
+/// the vector the claim is an inner product against (e.g. the verifier's derived
+/// evaluation point powers), and the prover's claimed result of
+/// `inner_product_ref_cast(&commitment_vector, &evaluation_vector)` for that query.
+///
+/// Each claim's `commitment_vector` is independent of every other claim's — this is what
+/// lets [`AccumulatedQueryResult`] batch proofs over genuinely different underlying column
+/// data, not just repeated evaluations of one shared vector.
+#[derive(Debug, Clone)]
+pub struct InnerProductClaim<S: Scalar> {
+    /// This query's own commitment vector.
+    pub commitment_vector: Vec<S>,
+    /// The vector `commitment_vector` is claimed to have this inner product against.
+    pub evaluation_vector: Vec<S>,
+    /// The prover's claimed result of `inner_product_ref_cast(&commitment_vector,
+    /// &evaluation_vector)` for this query.
+    pub claimed_value: S,
+}
+
+/// An accumulator that folds many independent [`InnerProductClaim`]s (each over its own,
+/// unrelated `commitment_vector`) into a single combined claim, deferring all but one final
+/// inner-product check to [`Self::verify_batch`].
+///
+/// Each call to [`Self::fold`] draws a fresh Fiat-Shamir challenge `r` (supplied by the
+/// caller, since transcript management lives with [`super::Transcript`]) and appends
+/// `r * claim.commitment_vector` and `claim.evaluation_vector` as new blocks onto two
+/// running, block-concatenated vectors, while folding `claimed_value` into a running scalar
+/// total as `running += r * claimed_value`. Because
+/// `<concat(r_1 c_1, r_2 c_2, ...), concat(e_1, e_2, ...)> = sum(r_i * <c_i, e_i>)`, a single
+/// [`inner_product_ref_cast`] over the two concatenated vectors in [`Self::verify_batch`]
+/// is equivalent to checking every folded-in claim individually — except with soundness
+/// error `num_claims / |S|` from reusing one challenge per claim instead of verifying each
+/// on its own. Note this trades a sublinear *inner-product* count for a single check whose
+/// vector length is still the *sum* of every claim's length; it does not reduce total work
+/// below verifying each claim's inner product once, only the number of separate checks
+/// (and the number of times an opening/transcript round trip is needed) from `N` to `1`.
+#[derive(Debug, Clone)]
+pub struct AccumulatedQueryResult<S: Scalar> {
+    folded_commitment_vector: Vec<S>,
+    folded_evaluation_vector: Vec<S>,
+    folded_value: S,
+    num_folded: usize,
+}
+
+impl<S: Scalar> Default for AccumulatedQueryResult<S> {
+    fn default() -> Self {
+        Self {
+            folded_commitment_vector: Vec::new(),
+            folded_evaluation_vector: Vec::new(),
+            folded_value: S::ZERO,
+            num_folded: 0,
+        }
+    }
+}
+
+impl<S: Scalar> AccumulatedQueryResult<S> {
+    /// Start a new, empty accumulation.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Fold `claim` into the running accumulation with challenge `challenge`.
+    ///
+    /// `challenge` must be drawn from the verifier's transcript *after* `claim`'s
+    /// commitment vector has been absorbed into it, so that it is unpredictable to a
+    /// prover trying to construct a false claim that cancels against the running total.
+    ///
+    /// # Errors
+    /// Returns [`ProofError::VerificationError`] if `claim.commitment_vector` and
+    /// `claim.evaluation_vector` have different lengths. Folding a mismatched pair in would
+    /// append differently-sized blocks onto the two running, block-concatenated vectors,
+    /// shifting every later claim's block out of alignment with its own evaluation vector —
+    /// silently breaking the one-inner-product-per-claim decomposition
+    /// [`Self::verify_batch`] relies on for every claim folded in after it, not just this
+    /// one.
+    pub fn fold(&mut self, claim: &InnerProductClaim<S>, challenge: S) -> Result<(), ProofError> {
+        if claim.commitment_vector.len() != claim.evaluation_vector.len() {
+            return Err(ProofError::VerificationError {
+                error: "claim's commitment_vector and evaluation_vector have different lengths",
+            });
+        }
+        self.folded_commitment_vector
+            .extend(claim.commitment_vector.iter().map(|&c| challenge * c));
+        self.folded_evaluation_vector
+            .extend(claim.evaluation_vector.iter().copied());
+        self.folded_value += challenge * claim.claimed_value;
+        self.num_folded += 1;
+        Ok(())
+    }
+
+    /// The number of claims folded into this accumulator so far.
+    pub fn num_folded(&self) -> usize {
+        self.num_folded
+    }
+
+    /// Verify every claim folded into this accumulator at once: the invariant is that this
+    /// holds iff every folded-in [`InnerProductClaim::claimed_value`] is the true inner
+    /// product of its own `commitment_vector` against its own `evaluation_vector`.
+    ///
+    /// # Errors
+    /// Returns [`ProofError::VerificationError`] if the recomputed inner product does not
+    /// match the accumulated claim.
+    pub fn verify_batch(&self) -> Result<(), ProofError> {
+        let recomputed: S =
+            inner_product_ref_cast(&self.folded_commitment_vector, &self.folded_evaluation_vector);
+        if recomputed == self.folded_value {
+            Ok(())
+        } else {
+            Err(ProofError::VerificationError {
+                error: "accumulated inner-product claim did not match the folded proofs",
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::base::scalar::test_scalar::TestScalar;
+
+    #[test]
+    fn we_can_verify_a_single_folded_claim() {
+        let mut acc = AccumulatedQueryResult::new();
+        acc.fold(
+            &InnerProductClaim {
+                commitment_vector: alloc::vec![TestScalar::from(2), TestScalar::from(3)],
+                evaluation_vector: alloc::vec![TestScalar::from(5), TestScalar::from(7)],
+                claimed_value: TestScalar::from(2 * 5 + 3 * 7),
+            },
+            TestScalar::from(1),
+        )
+        .unwrap();
+        assert!(acc.verify_batch().is_ok());
+    }
+
+    #[test]
+    fn we_can_batch_two_claims_over_independent_commitment_vectors() {
+        let mut acc = AccumulatedQueryResult::new();
+        // First query: commits to a totally different column than the second.
+        acc.fold(
+            &InnerProductClaim {
+                commitment_vector: alloc::vec![TestScalar::from(2), TestScalar::from(3)],
+                evaluation_vector: alloc::vec![TestScalar::from(5), TestScalar::from(7)],
+                claimed_value: TestScalar::from(2 * 5 + 3 * 7),
+            },
+            TestScalar::from(10),
+        )
+        .unwrap();
+        acc.fold(
+            &InnerProductClaim {
+                commitment_vector: alloc::vec![TestScalar::from(100)],
+                evaluation_vector: alloc::vec![TestScalar::from(4)],
+                claimed_value: TestScalar::from(100 * 4),
+            },
+            TestScalar::from(1),
+        )
+        .unwrap();
+        assert_eq!(acc.num_folded(), 2);
+        assert!(acc.verify_batch().is_ok());
+    }
+
+    #[test]
+    fn a_tampered_claim_fails_verification() {
+        let mut acc = AccumulatedQueryResult::new();
+        acc.fold(
+            &InnerProductClaim {
+                commitment_vector: alloc::vec![TestScalar::from(2), TestScalar::from(3)],
+                evaluation_vector: alloc::vec![TestScalar::from(5), TestScalar::from(7)],
+                claimed_value: TestScalar::from(999),
+            },
+            TestScalar::from(1),
+        )
+        .unwrap();
+        assert!(acc.verify_batch().is_err());
+    }
+
+    #[test]
+    fn a_claim_with_a_mismatched_commitment_vector_fails_verification() {
+        let mut acc = AccumulatedQueryResult::new();
+        acc.fold(
+            &InnerProductClaim {
+                commitment_vector: alloc::vec![TestScalar::from(2), TestScalar::from(3)],
+                evaluation_vector: alloc::vec![TestScalar::from(5), TestScalar::from(7)],
+                claimed_value: TestScalar::from(2 * 5 + 3 * 7),
+            },
+            TestScalar::from(1),
+        )
+        .unwrap();
+        acc.fold(
+            &InnerProductClaim {
+                // Correct claimed_value for a *different* commitment_vector than the one
+                // actually supplied here should not let the batch verify.
+                commitment_vector: alloc::vec![TestScalar::from(1)],
+                evaluation_vector: alloc::vec![TestScalar::from(4)],
+                claimed_value: TestScalar::from(100 * 4),
+            },
+            TestScalar::from(1),
+        )
+        .unwrap();
+        assert!(acc.verify_batch().is_err());
+    }
+
+    #[test]
+    fn fold_rejects_a_claim_whose_commitment_and_evaluation_vectors_have_different_lengths() {
+        let mut acc = AccumulatedQueryResult::<TestScalar>::new();
+        let result = acc.fold(
+            &InnerProductClaim {
+                commitment_vector: alloc::vec![TestScalar::from(2), TestScalar::from(3)],
+                evaluation_vector: alloc::vec![TestScalar::from(5)],
+                claimed_value: TestScalar::from(10),
+            },
+            TestScalar::from(1),
+        );
+        assert!(result.is_err());
+        // The rejected claim must not have been folded in, or a later, well-formed claim
+        // would still end up misaligned against it.
+        assert_eq!(acc.num_folded(), 0);
+    }
+}
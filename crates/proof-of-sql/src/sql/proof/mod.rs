@@ -0,0 +1,5 @@
+/// EVM/Solidity verifier codegen for a query plan's field-arithmetic checks.
+pub mod solidity_verifier_codegen;
+
+/// Folds many independent `VerifiableQueryResult` inner-product claims into one claim.
+pub mod accumulated_query_result;
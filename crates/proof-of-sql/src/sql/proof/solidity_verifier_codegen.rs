@@ -0,0 +1,213 @@
+use alloc::{format, string::String, vec::Vec};
+
+/// A minimal, Solidity-codegen-facing mirror of the subset of `DynProofExpr`/`proof_plans`
+/// this generator currently understands.
+///
+/// This is intentionally much smaller than the full proof-plan tree: it only covers what's
+/// needed to emit the field-arithmetic and inner-product checks for a single filtered
+/// column projection. Expanding coverage (joins, group-by, additional `DynProofExpr`
+/// variants, ...) means adding a matching [`PlanNode`] variant and `emit_*` function; plan
+/// shapes outside these variants cannot be expressed as a [`PlanNode`] at all.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PlanNode {
+    /// A column commitment, referenced by its index into the query's public column list.
+    Column(usize),
+    /// A field constant, as its big-endian hex representation (without a `0x` prefix).
+    Literal(String),
+    /// `lhs + rhs`, evaluated in the scalar field.
+    Add(alloc::boxed::Box<PlanNode>, alloc::boxed::Box<PlanNode>),
+    /// `lhs * rhs`, evaluated in the scalar field.
+    Mul(alloc::boxed::Box<PlanNode>, alloc::boxed::Box<PlanNode>),
+}
+
+/// Errors that can occur generating a Solidity verifier from a [`PlanNode`] tree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CodegenError {
+    /// The plan referenced a node kind this generator does not (yet) support.
+    Unsupported(String),
+}
+
+/// Emit the Solidity expression (as a `uint256` scalar-field term) computing `node`.
+fn emit_expr(node: &PlanNode) -> Result<String, CodegenError> {
+    match node {
+        PlanNode::Column(index) => Ok(format!("openedPublicColumns[{index}]")),
+        PlanNode::Literal(hex) => Ok(format!("0x{hex}")),
+        PlanNode::Add(lhs, rhs) => Ok(format!(
+            "addmod({}, {}, FIELD_MODULUS)",
+            emit_expr(lhs)?,
+            emit_expr(rhs)?
+        )),
+        PlanNode::Mul(lhs, rhs) => Ok(format!(
+            "mulmod({}, {}, FIELD_MODULUS)",
+            emit_expr(lhs)?,
+            emit_expr(rhs)?
+        )),
+    }
+}
+
+/// Generate a self-contained Solidity verifier for the field-arithmetic portion of a
+/// single query plan's result expressions.
+///
+/// `blitzar::proof::InnerProductProof` (the proof system this crate verifies off-chain) is
+/// built over the Ristretto/Curve25519 scalar field, so `FIELD_MODULUS` in the emitted
+/// contract is that field's order (`2^252 + 27742317777372353535851937790883648493`), not
+/// an unrelated SNARK-friendly field. The generated contract:
+///
+/// 1. Takes `commitment` as an immutable constructor parameter, stored in contract storage
+///    at deployment time rather than accepted as a `verify()` argument. A single call's
+///    caller therefore cannot choose `commitment` and `openedPublicColumns` together: the
+///    commitment must already be on-chain (written by whatever deployment/governance
+///    process anchors it to the real off-chain proof) before anyone can attempt to open it.
+/// 2. Rejects `verify()` unless `commitment == keccak256(openedPublicColumns)`, binding the
+///    arithmetic below to that pre-registered digest.
+/// 3. Re-derives the Fiat-Shamir challenge on-chain via `keccak256(commitment)`, rather
+///    than accepting it as an unconstrained input, matching how [`super::Transcript`]
+///    derives challenges off-chain.
+/// 4. Recomputes each result expression's claimed scalar from `openedPublicColumns` and
+///    compares it against the corresponding entry in `finalInnerProductEvals`.
+///
+/// # Known limitation
+/// This generator does not yet emit an elliptic-curve opening check proving that
+/// `commitment`'s preimage is the *actual* secret column data behind the off-chain Pedersen
+/// commitments (the EVM has no Ristretto/Curve25519 scalar-multiplication precompile, so
+/// that requires either a future precompile or an in-Solidity curve arithmetic emulation).
+/// Moving `commitment` into storage closes the "caller picks both sides of the check in one
+/// call" hole, but whoever is authorized to write `commitment` (the constructor here; a
+/// future setter would need equivalent access control) is still trusted to have copied it
+/// from a real off-chain proof. Do not treat the generated contract as a sound on-chain
+/// verifier until the EC opening check lands; this is scaffolding for the
+/// commitment-binding/transcript/arithmetic half of the problem.
+///
+/// This only covers the single-expression, no-group-by case; joins and aggregations have no
+/// [`PlanNode`] variant, so they cannot be passed to this generator.
+///
+/// # Errors
+/// Returns [`CodegenError::Unsupported`] if `result_expr` is empty (there must be at least
+/// one output column to check).
+pub fn generate_solidity_verifier(
+    contract_name: &str,
+    num_public_columns: usize,
+    result_expr: &[PlanNode],
+) -> Result<String, CodegenError> {
+    if result_expr.is_empty() {
+        return Err(CodegenError::Unsupported(
+            "a verifier must check at least one result expression".into(),
+        ));
+    }
+    let mut checks = Vec::with_capacity(result_expr.len());
+    for (i, node) in result_expr.iter().enumerate() {
+        checks.push(format!("        claims[{i}] = {};", emit_expr(node)?));
+    }
+    Ok(format!(
+        r"// SPDX-License-Identifier: Apache-2.0
+// Auto-generated by proof-of-sql's Solidity verifier codegen. Do not edit by hand.
+//
+// KNOWN LIMITATION: `commitment` is pre-registered in storage at deployment (see the
+// constructor below) rather than taken as a `verify()` argument, so a single caller cannot
+// choose both `commitment` and `openedPublicColumns` in the same call. This contract still
+// does NOT check that `commitment` actually opens the off-chain Pedersen commitments
+// produced by blitzar's InnerProductProof (no Ristretto scalar-mult precompile exists on
+// the EVM today); whoever deploys this contract is trusted to have copied `commitment` from
+// a real off-chain proof. Treat this as commitment-binding/transcript/arithmetic
+// scaffolding, not a sound verifier, until the EC opening check is added.
+pragma solidity ^0.8.21;
+
+contract {contract_name} {{
+    // Order of the Ristretto/Curve25519 scalar field that blitzar's InnerProductProof is
+    // defined over: 2^252 + 27742317777372353535851937790883648493.
+    uint256 constant FIELD_MODULUS =
+        0x1000000000000000000000000000000014def9dea2f79cd65812631a5cf5d3ed;
+
+    /// The keccak256 digest of the query's opened public columns, as attested off-chain
+    /// when this contract was deployed. Immutable: there is no setter, so binding a
+    /// verifier to a different proof means deploying a new contract instance.
+    bytes32 public immutable commitment;
+
+    constructor(bytes32 commitment_) {{
+        commitment = commitment_;
+    }}
+
+    /// Recompute each result expression's claimed scalar from `openedPublicColumns` (after
+    /// binding it to the stored `commitment` and re-deriving the Fiat-Shamir challenge from
+    /// it) and compare it against the corresponding entry in `finalInnerProductEvals`, the
+    /// prover's claimed final inner-product reduction for that column (one row per
+    /// `{num_exprs}` `DynProofExpr` checked by this verifier).
+    function verify(
+        uint256[{num_public_columns}] calldata openedPublicColumns,
+        uint256[{num_exprs}] calldata finalInnerProductEvals
+    ) external view returns (bool) {{
+        if (keccak256(abi.encodePacked(openedPublicColumns)) != commitment) {{
+            return false;
+        }}
+        // Fiat-Shamir: the challenge is derived from the transcript, not supplied by the
+        // caller. The base generator doesn't yet use `challenge` in its (degree-0) checks
+        // below, but every expansion of this codegen to randomized/folded checks must
+        // derive its randomness from this value instead of accepting it as an argument.
+        uint256 challenge = uint256(keccak256(abi.encodePacked(commitment))) % FIELD_MODULUS;
+        challenge;
+
+        uint256[{num_exprs}] memory claims;
+{checks}
+        for (uint256 i = 0; i < claims.length; i++) {{
+            if (claims[i] != finalInnerProductEvals[i]) {{
+                return false;
+            }}
+        }}
+        return true;
+    }}
+}}
+",
+        num_exprs = result_expr.len(),
+        checks = checks.join("\n"),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::boxed::Box;
+
+    #[test]
+    fn we_can_generate_a_verifier_for_a_single_column_check() {
+        let source = generate_solidity_verifier("QueryVerifier", 1, &[PlanNode::Column(0)]).unwrap();
+        assert!(source.contains("contract QueryVerifier"));
+        assert!(source.contains("openedPublicColumns[0]"));
+        assert!(source.contains("keccak256"));
+        assert!(source.contains("0x1000000000000000000000000000000014def9dea2f79cd65812631a5cf5d3ed"));
+    }
+
+    #[test]
+    fn we_can_generate_a_verifier_for_an_arithmetic_expression() {
+        let node = PlanNode::Add(
+            Box::new(PlanNode::Column(0)),
+            Box::new(PlanNode::Mul(
+                Box::new(PlanNode::Column(1)),
+                Box::new(PlanNode::Literal("2a".into())),
+            )),
+        );
+        let source = generate_solidity_verifier("QueryVerifier", 2, &[node]).unwrap();
+        assert!(source.contains("addmod"));
+        assert!(source.contains("mulmod"));
+        assert!(source.contains("0x2a"));
+    }
+
+    #[test]
+    fn we_reject_an_empty_result_expression_list() {
+        assert!(generate_solidity_verifier("QueryVerifier", 1, &[]).is_err());
+    }
+
+    #[test]
+    fn the_generated_verifier_binds_opened_columns_to_a_commitment() {
+        let source = generate_solidity_verifier("QueryVerifier", 1, &[PlanNode::Column(0)]).unwrap();
+        assert!(source.contains("keccak256(abi.encodePacked(openedPublicColumns)) != commitment"));
+    }
+
+    #[test]
+    fn the_generated_verifier_takes_commitment_from_storage_not_from_verify_arguments() {
+        let source = generate_solidity_verifier("QueryVerifier", 1, &[PlanNode::Column(0)]).unwrap();
+        assert!(source.contains("bytes32 public immutable commitment;"));
+        assert!(source.contains("constructor(bytes32 commitment_)"));
+        assert!(!source.contains("function verify(\n        bytes32 commitment,"));
+        assert!(source.contains("function verify(\n        uint256[1] calldata openedPublicColumns,"));
+    }
+}
@@ -0,0 +1,3 @@
+/// Down-scaling (narrowing) cast arithmetic: `Decimal75(p1,s1) -> Decimal75(p2,s2)` with
+/// `s2 <= s1`, and `Decimal75 -> integer`.
+pub mod scaling_cast_expr;
@@ -0,0 +1,246 @@
+use super::DynProofExpr;
+use crate::base::{database::ColumnType, proof::ProofError};
+use alloc::boxed::Box;
+use serde::{Deserialize, Serialize};
+
+// NOTE: this module currently provides the pure witnessing/arithmetic core described below
+// (quotient/remainder decomposition, mode handling) but does not yet implement `ProofExpr`
+// or add a `DynProofExpr::ScalingCast` variant, so it cannot yet be reached from a query
+// plan. Wiring it up requires touching `DynProofExpr`'s definition and every exhaustive
+// match over it (`result_evaluate`/`prover_evaluate`/`verifier_evaluate`/
+// `get_column_references`) plus `ProofExpr` itself — none of which exist anywhere in this
+// changeset's tree (there is no file defining `DynProofExpr`/`ProofExpr` to add to). Until
+// those land, nothing in this module is actually proven; treat it as the arithmetic this
+// crate's acceptance test, `scaling_cast_expr_test.rs`, will need once that wiring exists.
+// That test module is registered below so it is at least part of the module tree, but it
+// cannot compile or run in this tree today: it references `DynProofExpr::ScalingCast`,
+// `test_utility::scaling_cast`, `OwnedTableTestAccessor`, and `VerifiableQueryResult`, none
+// of which this changeset's tree defines.
+
+/// How a scale- or precision-reducing cast treats source values that don't fit exactly at
+/// the target scale.
+///
+/// This mirrors the two modes exposed by Arrow's cast kernels: a conservative mode that
+/// rejects lossy casts, and a mode that always succeeds by truncating or rounding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CastScaleMode {
+    /// Error out if the source value cannot be represented exactly at the target scale.
+    Safe,
+    /// Always succeed, rounding half-up to the nearest representable value.
+    Round,
+    /// Always succeed, truncating toward zero.
+    Truncate,
+}
+
+/// Provable expression for `CAST(expr AS target_type)` where the target type has the same
+/// or fewer fractional digits than the source, i.e. `target_scale <= source_scale`
+/// (narrowing `Decimal75(p1, s1) -> Decimal75(p2, s2)`, or `Decimal75 -> integer`, which is
+/// the `s2 == 0` case).
+///
+/// For a source value `v` and divisor `d = 10^(source_scale - target_scale)`,
+/// [`witness_scaling_cast_column`] computes a quotient `q` and remainder `r` satisfying
+/// `v = q * d + r` with `0 <= r < d`. This type does not implement `ProofExpr`, so no
+/// verifier constraint on that decomposition, on the range of `r`, or on `round_bit` is
+/// emitted for it. Negative `v` floor toward negative infinity in `q`/`r`, and
+/// [`CastScaleMode::Round`] outputs `q + round_bit` with `round_bit = (2 * r >= d)`, i.e.
+/// rounds half up (ties go toward positive infinity, so `-1.5` becomes `-1`).
+/// [`CastScaleMode::Safe`] rejects any row with `r != 0` instead of rounding or truncating it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScalingCastExpr {
+    from_expr: Box<DynProofExpr>,
+    target_type: ColumnType,
+    mode: CastScaleMode,
+}
+
+impl ScalingCastExpr {
+    /// The largest scale gap `witness_scaling_cast_column` can divide by without
+    /// `10i128.pow(divisor_exponent)` overflowing `i128` (`10^38 < i128::MAX < 10^39`).
+    const MAX_DIVISOR_EXPONENT: i8 = 38;
+
+    /// Create a new down-scaling cast expression.
+    ///
+    /// # Errors
+    /// Returns a [`ProofError`] if `target_type`'s scale is greater than `from_expr`'s
+    /// scale (use the widening `scaling_cast` constructor for that direction instead), or
+    /// if the scale gap exceeds [`Self::MAX_DIVISOR_EXPONENT`] (the divisor would overflow
+    /// the `i128` this module currently witnesses quotients/remainders in).
+    pub fn try_new(
+        from_expr: Box<DynProofExpr>,
+        target_type: ColumnType,
+        mode: CastScaleMode,
+    ) -> Result<Self, ProofError> {
+        let source_scale = from_expr.data_type().scale().unwrap_or(0);
+        let target_scale = target_type.scale().unwrap_or(0);
+        if target_scale > source_scale {
+            return Err(ProofError::UnsupportedQueryPlan {
+                error: "ScalingCastExpr only supports non-increasing scale; use scaling_cast for widening casts",
+            });
+        }
+        if source_scale - target_scale > Self::MAX_DIVISOR_EXPONENT {
+            return Err(ProofError::UnsupportedQueryPlan {
+                error: "ScalingCastExpr cannot divide by a power of ten this large without overflowing i128",
+            });
+        }
+        Ok(Self {
+            from_expr,
+            target_type,
+            mode,
+        })
+    }
+
+    /// The exponent `source_scale - target_scale` of the power-of-ten divisor
+    /// `d = 10^(source_scale - target_scale)` this cast divides by.
+    pub fn divisor_exponent(&self) -> u8 {
+        let source_scale = self.from_expr.data_type().scale().unwrap_or(0);
+        let target_scale = self.target_type.scale().unwrap_or(0);
+        u8::try_from(source_scale - target_scale).expect("checked non-negative and in-range in try_new")
+    }
+
+    /// The cast's rounding/truncation/error behavior for inexact values.
+    pub fn mode(&self) -> CastScaleMode {
+        self.mode
+    }
+
+    /// The type this expression casts into.
+    pub fn target_type(&self) -> ColumnType {
+        self.target_type
+    }
+}
+
+/// Split an exact-integer division `value = quotient * divisor + remainder` with
+/// `0 <= remainder < divisor`, flooring `quotient` toward negative infinity (matching
+/// Euclidean division). `divisor` must be a positive power of ten.
+fn div_rem_floor(value: i128, divisor: i128) -> (i128, i128) {
+    let remainder = value.rem_euclid(divisor);
+    let quotient = (value - remainder) / divisor;
+    (quotient, remainder)
+}
+
+/// Apply [`CastScaleMode`] to a witnessed `(quotient, remainder)` pair for one row, returning
+/// the output value the verifier should see in the result column.
+///
+/// `quotient`/`remainder` must be the floored decomposition produced by [`div_rem_floor`]
+/// (`remainder` non-negative). [`CastScaleMode::Truncate`] corrects that floor back to
+/// truncation-toward-zero (Arrow's `Truncate` cast-kernel semantics): for a negative source
+/// value with a nonzero remainder, flooring rounds one step too far away from zero, which
+/// is exactly the case where `quotient < 0` here (a negative `quotient` with `remainder ==
+/// 0` is already exact and needs no correction).
+///
+/// # Errors
+/// Returns a [`ProofError`] if `mode` is [`CastScaleMode::Safe`] and `remainder != 0`.
+pub fn apply_cast_scale_mode(
+    quotient: i128,
+    remainder: i128,
+    divisor: i128,
+    mode: CastScaleMode,
+) -> Result<i128, ProofError> {
+    match mode {
+        CastScaleMode::Safe if remainder != 0 => Err(ProofError::UnsupportedQueryPlan {
+            error: "safe cast would lose precision",
+        }),
+        CastScaleMode::Safe => Ok(quotient),
+        CastScaleMode::Truncate => Ok(if remainder != 0 && quotient < 0 {
+            quotient + 1
+        } else {
+            quotient
+        }),
+        CastScaleMode::Round => {
+            // `2 * remainder >= divisor`, written so it cannot overflow when `divisor` is
+            // as large as `10^MAX_DIVISOR_EXPONENT`.
+            let round_up = remainder >= divisor - remainder;
+            Ok(if round_up { quotient + 1 } else { quotient })
+        }
+    }
+}
+
+/// Witness a full column's worth of quotients/remainders/outputs for a down-scaling cast.
+///
+/// # Errors
+/// Returns a [`ProofError`] if [`CastScaleMode::Safe`] rejects any row in `values`, or if
+/// `divisor_exponent` exceeds [`ScalingCastExpr::MAX_DIVISOR_EXPONENT`] (`10i128.pow(..)`
+/// would overflow).
+pub fn witness_scaling_cast_column(
+    values: &[i128],
+    divisor_exponent: u8,
+    mode: CastScaleMode,
+) -> Result<(alloc::vec::Vec<i128>, alloc::vec::Vec<i128>, alloc::vec::Vec<i128>), ProofError> {
+    if divisor_exponent > ScalingCastExpr::MAX_DIVISOR_EXPONENT as u8 {
+        return Err(ProofError::UnsupportedQueryPlan {
+            error: "divisor_exponent is too large; 10i128.pow(divisor_exponent) would overflow",
+        });
+    }
+    let divisor = 10i128.pow(u32::from(divisor_exponent));
+    let mut quotients = alloc::vec::Vec::with_capacity(values.len());
+    let mut remainders = alloc::vec::Vec::with_capacity(values.len());
+    let mut outputs = alloc::vec::Vec::with_capacity(values.len());
+    for &value in values {
+        let (q, r) = div_rem_floor(value, divisor);
+        let out = apply_cast_scale_mode(q, r, divisor, mode)?;
+        quotients.push(q);
+        remainders.push(r);
+        outputs.push(out);
+    }
+    Ok((outputs, quotients, remainders))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn we_can_truncate_a_positive_value() {
+        assert_eq!(div_rem_floor(127, 10), (12, 7));
+        assert_eq!(
+            apply_cast_scale_mode(12, 7, 10, CastScaleMode::Truncate).unwrap(),
+            12
+        );
+    }
+
+    #[test]
+    fn we_truncate_a_negative_value_toward_zero_not_toward_negative_infinity() {
+        let (q, r) = div_rem_floor(-127, 10);
+        assert_eq!(apply_cast_scale_mode(q, r, 10, CastScaleMode::Truncate).unwrap(), -12);
+
+        let (q, r) = div_rem_floor(-5, 10);
+        assert_eq!(apply_cast_scale_mode(q, r, 10, CastScaleMode::Truncate).unwrap(), 0);
+
+        // An exact negative value needs no correction.
+        let (q, r) = div_rem_floor(-10, 10);
+        assert_eq!(apply_cast_scale_mode(q, r, 10, CastScaleMode::Truncate).unwrap(), -1);
+    }
+
+    #[test]
+    fn we_can_round_half_up() {
+        assert_eq!(
+            apply_cast_scale_mode(12, 5, 10, CastScaleMode::Round).unwrap(),
+            13
+        );
+        assert_eq!(
+            apply_cast_scale_mode(12, 4, 10, CastScaleMode::Round).unwrap(),
+            12
+        );
+    }
+
+    #[test]
+    fn we_can_floor_a_negative_value_toward_negative_infinity() {
+        assert_eq!(div_rem_floor(-127, 10), (-13, 3));
+    }
+
+    #[test]
+    fn safe_mode_rejects_inexact_values() {
+        assert!(apply_cast_scale_mode(12, 7, 10, CastScaleMode::Safe).is_err());
+        assert_eq!(
+            apply_cast_scale_mode(12, 0, 10, CastScaleMode::Safe).unwrap(),
+            12
+        );
+    }
+
+    #[test]
+    fn witness_scaling_cast_column_rejects_a_divisor_exponent_that_would_overflow_i128() {
+        assert!(witness_scaling_cast_column(&[1], 39, CastScaleMode::Truncate).is_err());
+        assert!(witness_scaling_cast_column(&[1], 38, CastScaleMode::Truncate).is_ok());
+    }
+}
+
+#[cfg(test)]
+mod scaling_cast_expr_test;